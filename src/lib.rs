@@ -1,14 +1,31 @@
 // postcard deps
-use postcard::{from_bytes, to_allocvec};
-use serde::{de::DeserializeOwned, Serialize};
+use postcard::{
+    accumulator::{CobsAccumulator, FeedResult},
+    from_bytes,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 // axum deps
 use async_trait::async_trait;
 use axum::{
     body::{Body, Bytes},
     extract::{rejection::BytesRejection, FromRequest},
-    http::{header, HeaderMap, Request, StatusCode},
+    http::{header, HeaderMap, HeaderValue, Request, StatusCode},
     response::{IntoResponse, Response},
 };
+// other deps
+use bytes::{BufMut, BytesMut};
+use crc::{Crc, CRC_32_ISCSI};
+use futures_util::Stream;
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Default value of [`Postcard`]'s `LIMIT` const parameter: the number of body bytes above which
+/// a request is rejected while it's being buffered.
+pub const DEFAULT_BODY_LIMIT: usize = 2 * 1024 * 1024;
 
 /// Postcard Extractor / Response.
 ///
@@ -21,6 +38,14 @@ use axum::{
 /// - The body contains syntactically valid Postcard but it couldn't be deserialized into the target
 /// type.
 /// - Buffering the request body fails.
+/// - The body is longer than `LIMIT` bytes.
+///
+/// `LIMIT` bounds the number of bytes that will ever be buffered into memory when extracting:
+/// the body is read through a capped reader, so a request is rejected with
+/// [`PostcardRejection::PayloadTooLarge`] as soon as more than `LIMIT` bytes have been read,
+/// regardless of whether (or how honestly) it sent a `Content-Length` header. It defaults to
+/// [`DEFAULT_BODY_LIMIT`]; set it explicitly (e.g. `Postcard<T, 1024>`) to pick a different
+/// ceiling.
 ///
 /// ⚠️ Since parsing Postcard requires consuming the request body, the `Postcard` extractor must be
 /// *last* if there are multiple extractors in a handler.
@@ -94,7 +119,7 @@ use axum::{
 /// # axum::serve(listener, app).await.unwrap();
 /// # };
 /// ```
-pub struct Postcard<T>(pub T);
+pub struct Postcard<T, const LIMIT: usize = DEFAULT_BODY_LIMIT>(pub T);
 
 #[derive(thiserror::Error, Debug)]
 pub enum PostcardRejection {
@@ -104,6 +129,16 @@ pub enum PostcardRejection {
     PostcardError(#[from] postcard::Error),
     #[error(transparent)]
     Bytes(#[from] BytesRejection),
+    #[error("a single postcard COBS frame exceeded the stream's accumulator buffer")]
+    FrameTooLarge,
+    #[error(transparent)]
+    Stream(#[from] axum::Error),
+    #[error("the request body exceeds the allowed limit")]
+    PayloadTooLarge,
+    #[error("the trailing CRC didn't match the decoded postcard body")]
+    ChecksumMismatch,
+    #[error("a postcard COBS frame in the stream failed to decode")]
+    FrameDecode,
 }
 
 impl IntoResponse for PostcardRejection {
@@ -115,22 +150,28 @@ impl IntoResponse for PostcardRejection {
                 (StatusCode::UNSUPPORTED_MEDIA_TYPE, self.to_string()).into_response()
             }
             PostcardError(err) => (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+            FrameTooLarge | PayloadTooLarge => {
+                (StatusCode::PAYLOAD_TOO_LARGE, self.to_string()).into_response()
+            }
+            ChecksumMismatch | FrameDecode => {
+                (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+            }
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()).into_response(),
         }
     }
 }
 
 #[async_trait]
-impl<T, S> FromRequest<S> for Postcard<T>
+impl<T, S, const LIMIT: usize> FromRequest<S> for Postcard<T, LIMIT>
 where
     T: DeserializeOwned,
     S: Send + Sync,
 {
     type Rejection = PostcardRejection;
 
-    async fn from_request(req: Request<Body>, state: &S) -> Result<Self, Self::Rejection> {
+    async fn from_request(req: Request<Body>, _state: &S) -> Result<Self, Self::Rejection> {
         if postcard_content_type(req.headers()) {
-            let bytes = Bytes::from_request(req, state).await?;
+            let bytes = buffer_with_limit(req.into_body(), LIMIT).await?;
 
             let value = match from_bytes(&*bytes) {
                 Ok(value) => value,
@@ -143,6 +184,26 @@ where
     }
 }
 
+/// Reads `body` into a single [`Bytes`] buffer, rejecting with
+/// [`PostcardRejection::PayloadTooLarge`] as soon as more than `limit` bytes have been read.
+///
+/// Unlike checking the `Content-Length` header, this caps the actual number of bytes buffered:
+/// it also catches bodies sent without a `Content-Length` (chunked transfer-encoding, HTTP/2) or
+/// with a dishonest one.
+async fn buffer_with_limit(body: Body, limit: usize) -> Result<Bytes, PostcardRejection> {
+    Limited::new(body, limit)
+        .collect()
+        .await
+        .map(|collected| collected.to_bytes())
+        .map_err(|err| {
+            if err.downcast_ref::<LengthLimitError>().is_some() {
+                PostcardRejection::PayloadTooLarge
+            } else {
+                PostcardRejection::Stream(axum::Error::new(err))
+            }
+        })
+}
+
 fn postcard_content_type(headers: &HeaderMap) -> bool {
     let content_type = if let Some(content_type) = headers.get(header::CONTENT_TYPE) {
         content_type
@@ -168,13 +229,454 @@ fn postcard_content_type(headers: &HeaderMap) -> bool {
     is_postcard_content_type
 }
 
+/// Default capacity, in bytes, of the [`BytesMut`] buffer [`Postcard`] serializes its response
+/// into. Matches the size hint serde itself uses for a fresh `Vec`.
+const DEFAULT_SER_CAPACITY: usize = 128;
+
+/// A [`postcard::ser_flavors::Flavor`] that writes into a [`BytesMut`], the same way axum's
+/// `Json` response writes into a `BytesMut` through `BufMut` instead of allocating a fresh `Vec`.
+struct BytesMutFlavor(BytesMut);
+
+impl postcard::ser_flavors::Flavor for BytesMutFlavor {
+    type Output = BytesMut;
+
+    fn try_push(&mut self, data: u8) -> postcard::Result<()> {
+        self.0.put_u8(data);
+        Ok(())
+    }
+
+    fn try_extend(&mut self, data: &[u8]) -> postcard::Result<()> {
+        self.0.put_slice(data);
+        Ok(())
+    }
+
+    fn finalize(self) -> postcard::Result<Self::Output> {
+        Ok(self.0)
+    }
+}
+
+fn postcard_response<T: Serialize>(value: &T, capacity: usize) -> Response {
+    match postcard::serialize_with_flavor(value, BytesMutFlavor(BytesMut::with_capacity(capacity)))
+    {
+        Ok(buf) => (
+            [(header::CONTENT_TYPE, "application/postcard")],
+            buf.freeze(),
+        )
+            .into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
+
+impl<T, const LIMIT: usize> Postcard<T, LIMIT>
+where
+    T: Serialize,
+{
+    /// Serializes into a response the same way [`IntoResponse::into_response`] does, but
+    /// pre-allocates the output buffer with `capacity` bytes instead of [`DEFAULT_SER_CAPACITY`].
+    ///
+    /// Useful for a hot endpoint that serves many small, similarly-sized postcard messages and
+    /// wants to avoid the buffer's own growth reallocations.
+    pub fn into_response_with_capacity(self, capacity: usize) -> Response {
+        postcard_response(&self.0, capacity)
+    }
+}
+
+// Implemented for the default `LIMIT` only (`LIMIT` matters only when extracting), so that a
+// bare `Postcard(value)` used as a response has a type the compiler can infer.
 impl<T> IntoResponse for Postcard<T>
 where
     T: Serialize,
 {
     fn into_response(self) -> Response {
-        // TODO: maybe use 128 bytes cause serde is doing something like that
-        match to_allocvec(&self.0) {
+        postcard_response(&self.0, DEFAULT_SER_CAPACITY)
+    }
+}
+
+/// A Postcard response that also sets `Content-Disposition: attachment`, prompting clients to
+/// save the body as a file instead of rendering it inline.
+///
+/// Mirrors the `Attachment` type added to axum-extra, but always serializes its inner value to
+/// postcard (via [`Postcard`]) rather than requiring `T: IntoResponse`. The `Content-Type` is
+/// `application/postcard` unless overridden with [`content_type`].
+///
+/// [`content_type`]: PostcardAttachment::content_type
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{routing::get, Router};
+/// use serde::Serialize;
+/// use axum_postcard::PostcardAttachment;
+///
+/// #[derive(Serialize)]
+/// struct Config {
+///     version: u32,
+/// }
+///
+/// async fn download_config() -> PostcardAttachment<Config> {
+///     PostcardAttachment::new(Config { version: 1 }).filename("config.postcard")
+/// }
+///
+/// let app = Router::new().route("/config", get(download_config));
+/// # async {
+/// # let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+/// # axum::serve(listener, app).await.unwrap();
+/// # };
+/// ```
+pub struct PostcardAttachment<T> {
+    value: T,
+    filename: Option<String>,
+    content_type: Option<HeaderValue>,
+}
+
+impl<T> PostcardAttachment<T> {
+    /// Wraps `value` as an attachment. Without [`filename`], the response carries a bare
+    /// `Content-Disposition: attachment` with no suggested name.
+    ///
+    /// [`filename`]: PostcardAttachment::filename
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            filename: None,
+            content_type: None,
+        }
+    }
+
+    /// Sets the filename clients should use when saving the response body.
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// Overrides the `Content-Type` header, for payloads that shouldn't be advertised as
+    /// `application/postcard` (e.g. a vendor-specific firmware or snapshot media type).
+    pub fn content_type(mut self, content_type: HeaderValue) -> Self {
+        self.content_type = Some(content_type);
+        self
+    }
+}
+
+impl<T> IntoResponse for PostcardAttachment<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> Response {
+        let mut res = Postcard(self.value).into_response();
+
+        if let Some(content_type) = self.content_type {
+            res.headers_mut().insert(header::CONTENT_TYPE, content_type);
+        }
+
+        res.headers_mut().insert(
+            header::CONTENT_DISPOSITION,
+            content_disposition(self.filename.as_deref()),
+        );
+
+        res
+    }
+}
+
+/// Builds a `Content-Disposition: attachment` header value, quoting `filename` when it fits in a
+/// quoted string and falling back to its RFC 5987 `filename*` form otherwise.
+fn content_disposition(filename: Option<&str>) -> HeaderValue {
+    let Some(filename) = filename else {
+        return HeaderValue::from_static("attachment");
+    };
+
+    if filename.is_ascii() && !filename.contains(['"', '\\', '\r', '\n']) {
+        if let Ok(value) = HeaderValue::from_str(&format!("attachment; filename=\"{filename}\"")) {
+            return value;
+        }
+    }
+
+    let encoded = percent_encode_filename(filename);
+    HeaderValue::from_str(&format!("attachment; filename*=UTF-8''{encoded}"))
+        .unwrap_or_else(|_| HeaderValue::from_static("attachment"))
+}
+
+fn percent_encode_filename(filename: &str) -> String {
+    let mut encoded = String::with_capacity(filename.len());
+    for byte in filename.as_bytes() {
+        match byte {
+            b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(*byte as char);
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Postcard Extractor for borrowed types.
+///
+/// Unlike [`Postcard`], which requires `T: DeserializeOwned` and deserializes eagerly in
+/// `from_request`, `PostcardDeserializer` only buffers the request body into [`Bytes`] and defers
+/// deserialization to [`PostcardDeserializer::deserialize`]. This lets `T` borrow directly out of
+/// the buffered bytes (e.g. structs with `&str` or `&[u8]` fields) instead of paying for the
+/// allocations that `DeserializeOwned` would force.
+///
+/// This mirrors axum's `JsonDeserializer` extractor, but for postcard.
+///
+/// The request will be rejected (and a [`PostcardRejection`] will be returned) if:
+///
+/// - The request doesn't have a `Content-Type: application/postcard` (or similar) header.
+/// - Buffering the request body fails.
+///
+/// Unlike [`Postcard`], syntax and type errors surface later, from [`PostcardDeserializer::deserialize`]
+/// rather than from the extractor itself.
+///
+/// ⚠️ Since parsing Postcard requires consuming the request body, the `PostcardDeserializer`
+/// extractor must be *last* if there are multiple extractors in a handler.
+/// See ["the order of extractors"][order-of-extractors]
+///
+/// [order-of-extractors]: axum::extract#the-order-of-extractors
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{
+///     routing::post,
+///     Router,
+/// };
+/// use serde::Deserialize;
+/// use axum_postcard::PostcardDeserializer;
+///
+/// #[derive(Deserialize)]
+/// struct CreateUser<'a> {
+///     email: &'a str,
+///     password: &'a str,
+/// }
+///
+/// async fn create_user(deserializer: PostcardDeserializer) {
+///     let payload: CreateUser = deserializer.deserialize().unwrap();
+///     todo!()
+/// }
+///
+/// let app = Router::new().route("/users", post(create_user));
+/// # async {
+/// # let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+/// # axum::serve(listener, app).await.unwrap();
+/// # };
+/// ```
+pub struct PostcardDeserializer(Bytes);
+
+impl PostcardDeserializer {
+    /// Deserialize the buffered request body into `T`.
+    ///
+    /// `T` may borrow out of `self`, since postcard can deserialize directly out of the
+    /// underlying byte slice.
+    pub fn deserialize<'de, T>(&'de self) -> Result<T, PostcardRejection>
+    where
+        T: Deserialize<'de>,
+    {
+        from_bytes(&self.0).map_err(PostcardRejection::PostcardError)
+    }
+}
+
+#[async_trait]
+impl<S> FromRequest<S> for PostcardDeserializer
+where
+    S: Send + Sync,
+{
+    type Rejection = PostcardRejection;
+
+    async fn from_request(req: Request<Body>, state: &S) -> Result<Self, Self::Rejection> {
+        if postcard_content_type(req.headers()) {
+            let bytes = Bytes::from_request(req, state).await?;
+            Ok(PostcardDeserializer(bytes))
+        } else {
+            Err(PostcardRejection::MissingPostcardContentType)
+        }
+    }
+}
+
+/// Default capacity, in bytes, of the accumulator buffer backing [`PostcardStream`].
+const DEFAULT_COBS_BUFFER_SIZE: usize = 2048;
+
+/// Streaming, COBS-framed Postcard Extractor.
+///
+/// Turns the request body into a [`Stream`] of `T`s, decoding one COBS frame (delimited by
+/// `0x00`) at a time rather than buffering the whole body up front. This is the postcard
+/// counterpart to axum-extra's JSON Lines extractor, except frames are delimited by COBS's
+/// `0x00` byte instead of newlines.
+///
+/// `N` is the size, in bytes, of the fixed-capacity buffer used to accumulate a single frame
+/// before it is decoded; it defaults to [`DEFAULT_COBS_BUFFER_SIZE`]. If a frame doesn't fit,
+/// the stream yields [`PostcardRejection::FrameTooLarge`].
+///
+/// The request will be rejected up front (and a [`PostcardRejection`] will be returned instead
+/// of a stream) if the request doesn't have a `Content-Type: application/postcard` (or similar)
+/// header. Once extraction succeeds, each item of the stream may independently fail with a
+/// [`PostcardRejection`] if a frame is malformed, too large, or the underlying body errors.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use axum::{routing::post, Router};
+/// use futures_util::StreamExt;
+/// use serde::Deserialize;
+/// use axum_postcard::PostcardStream;
+///
+/// #[derive(Deserialize)]
+/// struct Reading {
+///     celsius: f32,
+/// }
+///
+/// async fn ingest(mut stream: PostcardStream<Reading>) {
+///     while let Some(reading) = stream.next().await {
+///         let reading = reading.unwrap();
+///         todo!("do something with {}", reading.celsius);
+///     }
+/// }
+///
+/// let app = Router::new().route("/readings", post(ingest));
+/// # async {
+/// # let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+/// # axum::serve(listener, app).await.unwrap();
+/// # };
+/// ```
+pub struct PostcardStream<T, const N: usize = DEFAULT_COBS_BUFFER_SIZE> {
+    body: Pin<Box<dyn Stream<Item = Result<Bytes, axum::Error>> + Send>>,
+    accumulator: CobsAccumulator<N>,
+    pending: Bytes,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[async_trait]
+impl<T, S, const N: usize> FromRequest<S> for PostcardStream<T, N>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = PostcardRejection;
+
+    async fn from_request(req: Request<Body>, _state: &S) -> Result<Self, Self::Rejection> {
+        if postcard_content_type(req.headers()) {
+            Ok(PostcardStream {
+                body: Box::pin(req.into_body().into_data_stream()),
+                accumulator: CobsAccumulator::new(),
+                pending: Bytes::new(),
+                _marker: PhantomData,
+            })
+        } else {
+            Err(PostcardRejection::MissingPostcardContentType)
+        }
+    }
+}
+
+impl<T, const N: usize> Stream for PostcardStream<T, N>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<T, PostcardRejection>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if !this.pending.is_empty() {
+                let pending = std::mem::take(&mut this.pending);
+                match this.accumulator.feed::<T>(&pending) {
+                    FeedResult::Consumed => continue,
+                    FeedResult::OverFull(remaining) => {
+                        this.pending = Bytes::copy_from_slice(remaining);
+                        return Poll::Ready(Some(Err(PostcardRejection::FrameTooLarge)));
+                    }
+                    FeedResult::DeserError(remaining) => {
+                        this.pending = Bytes::copy_from_slice(remaining);
+                        // `CobsAccumulator` doesn't hand back the underlying `postcard::Error`
+                        // here, so there's no real error to wrap in `PostcardError` — this is a
+                        // dedicated variant rather than a fabricated postcard error code.
+                        return Poll::Ready(Some(Err(PostcardRejection::FrameDecode)));
+                    }
+                    FeedResult::Success { data, remaining } => {
+                        this.pending = Bytes::copy_from_slice(remaining);
+                        return Poll::Ready(Some(Ok(data)));
+                    }
+                }
+            }
+
+            match this.body.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => this.pending = chunk,
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err.into()))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// CRC-32 instance (CRC-32C / iSCSI polynomial) used to checksum [`PostcardCrc`] bodies.
+static CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_ISCSI);
+
+/// Postcard Extractor / Response with a trailing CRC-32 integrity check.
+///
+/// Like [`Postcard`], but the serialized form has a CRC-32 appended after the postcard data
+/// (via postcard's CRC [`Flavor`][postcard::ser_flavors::Flavor]), and extraction verifies that
+/// checksum before deserializing. Embedded or lossy-link peers that speak postcard often want
+/// this kind of on-the-wire integrity check without layering their own framing; callers who
+/// don't need it can keep using [`Postcard`] unchanged.
+///
+/// The request will be rejected (and a [`PostcardRejection`] will be returned) for the same
+/// reasons as [`Postcard`], plus:
+///
+/// - The trailing CRC doesn't match the decoded body ([`PostcardRejection::ChecksumMismatch`]).
+///
+/// # Extractor example
+///
+/// ```rust,no_run
+/// use axum::{routing::post, Router};
+/// use serde::Deserialize;
+/// use axum_postcard::PostcardCrc;
+///
+/// #[derive(Deserialize)]
+/// struct Reading {
+///     celsius: f32,
+/// }
+///
+/// async fn ingest(PostcardCrc(reading): PostcardCrc<Reading>) {
+///     todo!("do something with {}", reading.celsius);
+/// }
+///
+/// let app = Router::new().route("/readings", post(ingest));
+/// # async {
+/// # let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+/// # axum::serve(listener, app).await.unwrap();
+/// # };
+/// ```
+pub struct PostcardCrc<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for PostcardCrc<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = PostcardRejection;
+
+    async fn from_request(req: Request<Body>, state: &S) -> Result<Self, Self::Rejection> {
+        if postcard_content_type(req.headers()) {
+            let bytes = Bytes::from_request(req, state).await?;
+
+            let value = match postcard::from_bytes_crc32(&bytes, CRC32.digest()) {
+                Ok(value) => value,
+                Err(postcard::Error::DeserializeBadCrc) => {
+                    return Err(PostcardRejection::ChecksumMismatch)
+                }
+                Err(err) => return Err(PostcardRejection::PostcardError(err)),
+            };
+            Ok(PostcardCrc(value))
+        } else {
+            Err(PostcardRejection::MissingPostcardContentType)
+        }
+    }
+}
+
+impl<T> IntoResponse for PostcardCrc<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> Response {
+        match postcard::to_allocvec_crc32(&self.0, CRC32.digest()) {
             Ok(value) => ([(header::CONTENT_TYPE, "application/postcard")], value).into_response(),
             Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
         }
@@ -186,8 +688,8 @@ mod tests {
     use super::*;
     use axum::{routing::post, Router};
     use axum_test_helpers::*;
-    use serde::Deserialize;
     use futures_util::StreamExt;
+    use serde::Deserialize;
 
     #[tokio::test]
     async fn deserialize_body() {
@@ -264,6 +766,20 @@ mod tests {
         assert_eq!(res.status(), StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn body_over_limit_is_rejected() {
+        let app = Router::new().route("/", post(|_: Postcard<String, 2>| async {}));
+
+        let client = TestClient::new(app);
+        let res = client
+            .post("/")
+            .header("content-type", "application/postcard")
+            .body("\x03bar")
+            .await;
+
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
     #[derive(Deserialize)]
     struct Foo {
         #[allow(dead_code)]
@@ -310,4 +826,150 @@ mod tests {
 
         assert_eq!(body, b"\x03bar");
     }
+
+    #[tokio::test]
+    async fn attachment_sets_content_disposition_with_filename() {
+        let response = PostcardAttachment::new("bar")
+            .filename("snapshot.postcard")
+            .into_response();
+
+        assert!(postcard_content_type(response.headers()));
+        assert_eq!(
+            response.headers().get(header::CONTENT_DISPOSITION).unwrap(),
+            "attachment; filename=\"snapshot.postcard\"",
+        );
+    }
+
+    #[tokio::test]
+    async fn attachment_without_filename_is_bare() {
+        let response = PostcardAttachment::new("bar").into_response();
+
+        assert_eq!(
+            response.headers().get(header::CONTENT_DISPOSITION).unwrap(),
+            "attachment",
+        );
+    }
+
+    #[tokio::test]
+    async fn deserializer_borrows_from_body() {
+        #[derive(Debug, Deserialize)]
+        struct Input<'a> {
+            foo: &'a str,
+        }
+
+        let app = Router::new().route(
+            "/",
+            post(|deserializer: PostcardDeserializer| async move {
+                let input: Input = deserializer.deserialize().unwrap();
+                input.foo.to_owned()
+            }),
+        );
+
+        let client = TestClient::new(app);
+
+        let res = client
+            .post("/")
+            .header("content-type", "application/postcard")
+            .body("\x03bar")
+            .await;
+        let body = res.text().await;
+
+        assert_eq!(body, "bar");
+    }
+
+    #[tokio::test]
+    async fn deserializer_requires_postcard_content_type() {
+        let app = Router::new().route("/", post(|_: PostcardDeserializer| async {}));
+
+        let client = TestClient::new(app);
+        let res = client.post("/").body("\x03bar").await;
+
+        assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[tokio::test]
+    async fn stream_decodes_multiple_cobs_frames() {
+        #[derive(Debug, Deserialize, Serialize)]
+        struct Reading {
+            celsius: u8,
+        }
+
+        let app = Router::new().route(
+            "/",
+            post(|mut stream: PostcardStream<Reading>| async move {
+                let mut readings = Vec::new();
+                while let Some(reading) = stream.next().await {
+                    readings.push(reading.unwrap().celsius.to_string());
+                }
+                readings.join(",")
+            }),
+        );
+
+        let mut body = postcard::to_allocvec_cobs(&Reading { celsius: 1 }).unwrap();
+        body.extend(postcard::to_allocvec_cobs(&Reading { celsius: 2 }).unwrap());
+
+        let client = TestClient::new(app);
+        let res = client
+            .post("/")
+            .header("content-type", "application/postcard")
+            .body(body)
+            .await;
+
+        assert_eq!(res.text().await, "1,2");
+    }
+
+    #[tokio::test]
+    async fn stream_requires_postcard_content_type() {
+        let app = Router::new().route("/", post(|_: PostcardStream<String>| async {}));
+
+        let client = TestClient::new(app);
+        let res = client.post("/").body("\x03bar\x00").await;
+
+        assert_eq!(res.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[tokio::test]
+    async fn crc_roundtrips_through_extractor_and_response() {
+        #[derive(Debug, Deserialize, Serialize)]
+        struct Input {
+            foo: String,
+        }
+
+        let app = Router::new().route(
+            "/",
+            post(|PostcardCrc(input): PostcardCrc<Input>| async move { PostcardCrc(input) }),
+        );
+
+        let body =
+            postcard::to_allocvec_crc32(&Input { foo: "bar".into() }, CRC32.digest()).unwrap();
+
+        let client = TestClient::new(app);
+        let res = client
+            .post("/")
+            .header("content-type", "application/postcard")
+            .body(body)
+            .await;
+
+        let body = res.bytes().await;
+        let Input { foo } = postcard::from_bytes_crc32(&body, CRC32.digest()).unwrap();
+        assert_eq!(foo, "bar");
+    }
+
+    #[tokio::test]
+    async fn crc_mismatch_is_rejected() {
+        let app = Router::new().route("/", post(|_: PostcardCrc<String>| async {}));
+
+        let mut body = postcard::to_allocvec_crc32(&"hi".to_string(), CRC32.digest()).unwrap();
+        let last = body.len() - 1;
+        body[last] ^= 0xff;
+
+        let client = TestClient::new(app);
+        let res = client
+            .post("/")
+            .header("content-type", "application/postcard")
+            .body(body)
+            .await;
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
 }